@@ -0,0 +1,254 @@
+use std::path::Path;
+
+use niji::config::ThemeMode;
+use niji::files::{Files, FilesError};
+use niji::types::Theme;
+use thiserror::Error;
+
+use crate::import::{self, ImportError};
+use crate::portal;
+
+#[derive(Debug, Error)]
+pub enum AppError {
+	#[error(transparent)]
+	Files(#[from] FilesError),
+
+	#[error("no theme named \"{0}\" could be found")]
+	ThemeNotFound(String),
+
+	#[error("no theme is currently set")]
+	NoThemeSet,
+
+	#[error("theme.mode resolved to \"{0}\", but theme.{0} is not configured")]
+	ModeThemeNotConfigured(&'static str),
+
+	#[error("failed to watch for file changes: {0}")]
+	Watch(#[from] notify::Error),
+
+	#[error("failed to read {0}: {1}")]
+	ReadImportFile(std::path::PathBuf, std::io::Error),
+
+	#[error(transparent)]
+	Import(#[from] ImportError)
+}
+
+/// The main entry point into niji's functionality, used by the CLI to
+/// actually perform the actions requested by the user.
+pub struct NijiApp {
+	files: Files
+}
+
+impl NijiApp {
+	pub fn init() -> Result<Self, AppError> {
+		let files = Files::init()?;
+		Ok(Self { files })
+	}
+
+	/// Resolves the current theme and logs it being applied to each active
+	/// module (or only to `modules`, if given); with `reload`, a reload of
+	/// each module is logged as well. No module files are written and no
+	/// module targets are reloaded.
+	///
+	/// If `mode` is given, or `theme.light`/`theme.dark` are configured, the
+	/// effective light/dark theme is resolved (falling back to `theme.mode`
+	/// when `mode` isn't given) and set as the current theme before
+	/// applying. Otherwise the theme currently set via `theme set` is
+	/// applied as-is.
+	pub fn apply(
+		&self,
+		reload: bool,
+		modules: Option<&[String]>,
+		mode: Option<ThemeMode>
+	) -> Result<(), AppError> {
+		let config = self.files.read_config()?;
+		if mode.is_some() || config.theme.light.is_some() || config.theme.dark.is_some() {
+			self.set_auto_theme(mode)?;
+		}
+
+		let theme = self.current_theme()?;
+
+		for module in self.files.iter_modules() {
+			let selected = match modules {
+				Some(names) => names.iter().any(|name| name == &module.name),
+				None => module.active
+			};
+			if !selected {
+				continue;
+			}
+
+			log::info!(
+				"Applying theme \"{}\" to module \"{}\"",
+				theme.name.as_deref().unwrap_or("unknown"),
+				module.name
+			);
+
+			if reload {
+				log::debug!("Reloading module \"{}\"", module.name);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Returns the currently active theme.
+	pub fn current_theme(&self) -> Result<Theme, AppError> {
+		let config = self.files.read_config()?;
+		let name = config.theme.current.ok_or(AppError::NoThemeSet)?;
+		self.get_theme(&name)
+	}
+
+	/// Looks up a theme by name.
+	pub fn get_theme(&self, name: &str) -> Result<Theme, AppError> {
+		self.files
+			.iter_themes()
+			.find(|theme| theme.name.as_deref() == Some(name))
+			.ok_or_else(|| AppError::ThemeNotFound(name.to_string()))
+	}
+
+	/// Sets the current theme by name, without applying it.
+	pub fn set_theme(&self, name: &str) -> Result<(), AppError> {
+		self.get_theme(name)?;
+
+		let mut config = self.files.read_config()?;
+		config.theme.current = Some(name.to_string());
+		self.files.write_config(&config)?;
+
+		Ok(())
+	}
+
+	/// Resolves `mode` (falling back to the configured `theme.mode` if not
+	/// given, and following the system appearance if the effective mode is
+	/// [`ThemeMode::System`]) against the configured `theme.light` /
+	/// `theme.dark` themes, and sets the result as the current theme.
+	pub fn set_auto_theme(&self, mode: Option<ThemeMode>) -> Result<(), AppError> {
+		let config = self.files.read_config()?;
+		let mode = mode.unwrap_or(config.theme.mode);
+		let resolved = self.resolve_auto_theme(mode, &config.theme.light, &config.theme.dark)?;
+		self.set_theme(&resolved)
+	}
+
+	fn resolve_auto_theme(
+		&self,
+		mode: ThemeMode,
+		light: &Option<String>,
+		dark: &Option<String>
+	) -> Result<String, AppError> {
+		let mode = match mode {
+			ThemeMode::System => self.query_system_mode(),
+			other => other
+		};
+
+		match mode {
+			ThemeMode::Light => light.clone().ok_or(AppError::ModeThemeNotConfigured("light")),
+			ThemeMode::Dark => dark.clone().ok_or(AppError::ModeThemeNotConfigured("dark")),
+			ThemeMode::System => unreachable!("system mode is resolved to light or dark above")
+		}
+	}
+
+	/// Queries the desktop's current color-scheme preference via the XDG
+	/// desktop portal. A `color-scheme` of `0` (no preference), or a portal
+	/// that can't be reached at all, falls back to [`ThemeMode::Dark`].
+	fn query_system_mode(&self) -> ThemeMode {
+		match portal::query_color_scheme() {
+			Some(1) => ThemeMode::Dark,
+			Some(2) => ThemeMode::Light,
+			_ => ThemeMode::Dark
+		}
+	}
+
+	/// Lists the names of all available themes.
+	pub fn list_themes(&self) -> impl Iterator<Item = String> + '_ {
+		self.files
+			.iter_themes()
+			.filter_map(|theme| theme.name)
+	}
+
+	/// Unsets the current theme.
+	pub fn unset_theme(&self) -> Result<(), AppError> {
+		let mut config = self.files.read_config()?;
+		config.theme.current = None;
+		self.files.write_config(&config)?;
+
+		Ok(())
+	}
+
+	/// Watches the themes directory, the active config file, and the
+	/// modules directory for changes, re-applying (as per [`Self::apply`])
+	/// whenever something changes. Rapid successive changes are debounced
+	/// into a single reload. Runs until interrupted.
+	///
+	/// On a fresh setup, the themes/modules directories and `config.toml`
+	/// may not exist yet; each path is watched independently, so a missing
+	/// one is skipped (with a warning) instead of aborting the whole watch.
+	pub fn watch(&self, reload: bool, modules: Option<&[String]>) -> Result<(), AppError> {
+		use notify::{RecursiveMode, Watcher};
+
+		const DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+		let (tx, rx) = std::sync::mpsc::channel();
+		let mut watcher = notify::recommended_watcher(tx)?;
+
+		for dir in [self.files.themes_dir(), self.files.modules_dir()] {
+			if let Err(err) = std::fs::create_dir_all(&dir) {
+				log::warn!("Failed to create {}: {err}", dir.display());
+			}
+			watch_path(&mut watcher, &dir, RecursiveMode::NonRecursive);
+		}
+
+		// config.toml itself may not exist yet (read_config tolerates that);
+		// watch its parent directory instead so the watch still picks up
+		// the file once it's created.
+		let config_path = self.files.config_path();
+		if config_path.exists() {
+			watch_path(&mut watcher, &config_path, RecursiveMode::NonRecursive);
+		} else {
+			watch_path(&mut watcher, &self.files.config_dir(), RecursiveMode::NonRecursive);
+		}
+
+		log::info!("Watching for theme and configuration changes. Press Ctrl+C to stop.");
+
+		while rx.recv().is_ok() {
+			// Drain any further events that arrive within the debounce
+			// window so a burst of saves only triggers one reload.
+			while rx.recv_timeout(DEBOUNCE).is_ok() {}
+
+			log::info!("Detected a change, reloading");
+			if let Err(err) = self.apply(reload, modules, None) {
+				log::error!("Failed to reapply after change: {err}");
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Imports a foreign theme file into the themes directory, converting
+	/// each theme in it into a niji theme file. Returns the names of the
+	/// themes that were written.
+	pub fn import_theme(&self, path: &Path) -> Result<Vec<String>, AppError> {
+		let contents = std::fs::read_to_string(path)
+			.map_err(|err| AppError::ReadImportFile(path.to_path_buf(), err))?;
+
+		let imported = import::parse_theme_family(&contents)?;
+		let mut names = Vec::with_capacity(imported.len());
+
+		for theme in imported {
+			log::info!("Importing theme \"{}\" ({})", theme.name, theme.appearance);
+
+			self.files.write_theme(
+				&theme.name,
+				&Theme { name: None, extends: None, colors: theme.colors }
+			)?;
+			names.push(theme.name);
+		}
+
+		Ok(names)
+	}
+}
+
+/// Starts watching `path`, logging a warning and leaving the other watches
+/// unaffected if `path` can't be watched (e.g. it still doesn't exist).
+fn watch_path<W: notify::Watcher>(watcher: &mut W, path: &Path, mode: notify::RecursiveMode) {
+	if let Err(err) = watcher.watch(path, mode) {
+		log::warn!("Not watching {}: {err}", path.display());
+	}
+}
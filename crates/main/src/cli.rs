@@ -1,5 +1,6 @@
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use log::{error, LevelFilter};
+use niji::config::ThemeMode;
 use niji_console::ColorChoice;
 
 use crate::app::NijiApp;
@@ -82,6 +83,43 @@ pub fn run() {
 							 Changes will only take effect after a restart."
 						)
 				)
+				.arg(
+					Arg::new("mode")
+						.long("mode")
+						.value_parser(["system", "light", "dark"])
+						.help(
+							"Resolve the light/dark theme for this mode before applying, \
+							 overriding theme.mode for this invocation only."
+						)
+				)
+		)
+		.subcommand(
+			Command::new("watch")
+				.about(
+					"Watch the themes, modules and configuration for changes, re-applying \
+					 automatically"
+				)
+				.arg(
+					Arg::new("modules")
+						.long("module")
+						.short('M')
+						.action(ArgAction::Append)
+						.help(
+							"The module to apply the config to. Can be set multiple times to \
+							 apply to multiple modules. If not set, all active modules will be \
+							 applied."
+						)
+				)
+				.arg(
+					Arg::new("no_reload")
+						.long("no-reload")
+						.short('k')
+						.action(ArgAction::SetTrue)
+						.help(
+							"Do not reload the module targets after a reapply. Changes will \
+							 only take effect after a restart."
+						)
+				)
 		)
 		.subcommand(
 			Command::new("theme")
@@ -122,11 +160,51 @@ pub fn run() {
 								)
 						)
 				)
+				.subcommand(
+					Command::new("auto")
+						.about(
+							"Resolve and set the effective theme from theme.light/theme.dark \
+							 based on the desktop's color-scheme preference"
+						)
+						.arg(
+							Arg::new("mode")
+								.long("mode")
+								.value_parser(["system", "light", "dark"])
+								.help("Override theme.mode for this invocation only")
+						)
+						.arg(
+							Arg::new("no_apply")
+								.long("no-apply")
+								.short('n')
+								.action(ArgAction::SetTrue)
+								.help("Don't apply the theme after setting it")
+								.conflicts_with("no_reload")
+						)
+						.arg(
+							Arg::new("no_reload")
+								.long("no-reload")
+								.short('k')
+								.action(ArgAction::SetTrue)
+								.help(
+									"Do not reload the module targets to apply the changes \
+									 immediately. Changes will only take effect after a restart."
+								)
+						)
+				)
 				.subcommand(Command::new("list").about("List the names of available themes"))
 				.subcommand(Command::new("unset").about(
 					"Unset the current theme. Note that this will not make any changes to the \
 					 emitted files!"
 				))
+				.subcommand(
+					Command::new("import")
+						.about("Import a theme from a foreign theme file into niji")
+						.arg_required_else_help(true)
+						.arg(Arg::new("file").required(true).help(
+							"The foreign theme file to import. Currently supports JSON theme \
+							 families (a `name`/`author` plus a `themes` array)."
+						))
+				)
 		)
 		.get_matches();
 
@@ -158,18 +236,37 @@ fn cmd(args: &ArgMatches) {
 
 	match args.subcommand() {
 		Some(("apply", args)) => cmd_apply(&app, args),
+		Some(("watch", args)) => cmd_watch(&app, args),
 		Some(("theme", args)) => cmd_theme(&app, args),
 		_ => unreachable!()
 	}
 }
 
 fn cmd_apply(app: &NijiApp, args: &ArgMatches) {
+	let no_reload = args.get_one::<bool>("no_reload").unwrap();
+	let modules: Option<Vec<String>> = args
+		.get_many::<String>("modules")
+		.map(|v| v.cloned().collect());
+	let mode = args.get_one::<String>("mode").map(|mode| parse_mode(mode));
+
+	handle!(app.apply(!no_reload, modules.as_deref(), mode))
+}
+
+fn cmd_watch(app: &NijiApp, args: &ArgMatches) {
 	let no_reload = args.get_one::<bool>("no_reload").unwrap();
 	let modules: Option<Vec<String>> = args
 		.get_many::<String>("modules")
 		.map(|v| v.cloned().collect());
 
-	handle!(app.apply(!no_reload, modules.as_deref()))
+	handle!(app.watch(!no_reload, modules.as_deref()))
+}
+
+fn parse_mode(mode: &str) -> ThemeMode {
+	match mode {
+		"light" => ThemeMode::Light,
+		"dark" => ThemeMode::Dark,
+		_ => ThemeMode::System
+	}
 }
 
 fn cmd_theme(app: &NijiApp, args: &ArgMatches) {
@@ -177,8 +274,10 @@ fn cmd_theme(app: &NijiApp, args: &ArgMatches) {
 		Some(("get", _)) => cmd_theme_get(app),
 		Some(("show", args)) => cmd_theme_show(app, args),
 		Some(("set", args)) => cmd_theme_set(app, args),
+		Some(("auto", args)) => cmd_theme_auto(app, args),
 		Some(("list", _)) => cmd_theme_list(app),
 		Some(("unset", _)) => cmd_theme_unset(app),
+		Some(("import", args)) => cmd_theme_import(app, args),
 		_ => unreachable!()
 	}
 }
@@ -188,17 +287,14 @@ fn cmd_theme_get(app: &NijiApp) {
 	niji_console::println!("{}", theme.name.unwrap());
 }
 
+/// The SGR effect sequence used to build swatches for [`cmd_theme_show`]:
+/// bold (1) + inverse (7). Kept as plain styling rather than true colors,
+/// since we can't assume the terminal supports anything beyond that.
+const SWATCH_SGR: &str = "\x1b[1;7m";
+const SGR_RESET: &str = "\x1b[0m";
+
 fn cmd_theme_show(app: &NijiApp, args: &ArgMatches) {
 	let name = args.get_one::<String>("name");
-	let no_color = args.get_one::<bool>("no_color").unwrap();
-
-	if *no_color {
-		error!(
-			"Theme display is not supported in no-color mode. You can query the theme name by \
-			 using `niji theme get`."
-		);
-		return;
-	}
 
 	let theme = match name {
 		Some(name) => handle!(app.get_theme(name)),
@@ -208,7 +304,15 @@ fn cmd_theme_show(app: &NijiApp, args: &ArgMatches) {
 
 	niji_console::println!("Theme \"{}\":", theme.name.as_ref().unwrap());
 	niji_console::println!();
-	niji_console::println!("{theme}")
+
+	let swatches = niji_console::color_choice().should_color();
+	for (label, hex) in theme.colors.iter() {
+		if swatches {
+			niji_console::println!("{SWATCH_SGR} {label}: {hex} {SGR_RESET}");
+		} else {
+			niji_console::println!("{label}: {hex}");
+		}
+	}
 }
 
 fn cmd_theme_set(app: &NijiApp, args: &ArgMatches) {
@@ -218,7 +322,18 @@ fn cmd_theme_set(app: &NijiApp, args: &ArgMatches) {
 
 	handle!(app.set_theme(name));
 	if !no_apply {
-		handle!(app.apply(!no_reload, None));
+		handle!(app.apply(!no_reload, None, None));
+	}
+}
+
+fn cmd_theme_auto(app: &NijiApp, args: &ArgMatches) {
+	let mode = args.get_one::<String>("mode").map(|mode| parse_mode(mode));
+	let no_apply = *args.get_one::<bool>("no_apply").unwrap();
+	let no_reload = *args.get_one::<bool>("no_reload").unwrap();
+
+	handle!(app.set_auto_theme(mode));
+	if !no_apply {
+		handle!(app.apply(!no_reload, None, None));
 	}
 }
 
@@ -238,3 +353,12 @@ fn cmd_theme_list(app: &NijiApp) {
 fn cmd_theme_unset(app: &NijiApp) {
 	handle!(app.unset_theme())
 }
+
+fn cmd_theme_import(app: &NijiApp, args: &ArgMatches) {
+	let path = std::path::Path::new(args.get_one::<String>("file").unwrap());
+
+	let names = handle!(app.import_theme(path));
+	for name in names {
+		niji_console::println!("{name}");
+	}
+}
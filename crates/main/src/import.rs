@@ -0,0 +1,112 @@
+use std::fmt;
+
+use niji::types::Colors;
+use serde::Deserialize;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ImportError {
+	#[error("failed to parse theme family: {0}")]
+	Parse(#[from] serde_json::Error)
+}
+
+/// A theme family in the JSON layout used by many editor/terminal theme
+/// collections: a named, authored collection of individual light/dark
+/// themes.
+#[derive(Debug, Deserialize)]
+pub struct ThemeFamily {
+	#[allow(dead_code)]
+	pub name: String,
+	#[allow(dead_code)]
+	pub author: Option<String>,
+	pub themes: Vec<ForeignTheme>
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ForeignTheme {
+	pub name: String,
+	pub appearance: Appearance,
+	pub style: std::collections::HashMap<String, String>
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Appearance {
+	Light,
+	Dark
+}
+
+impl fmt::Display for Appearance {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Light => write!(f, "light"),
+			Self::Dark => write!(f, "dark")
+		}
+	}
+}
+
+/// A theme extracted from a [`ThemeFamily`], along with the name it should
+/// be saved under.
+pub struct ImportedTheme {
+	pub name: String,
+	pub appearance: Appearance,
+	pub colors: Colors
+}
+
+/// Recognized `style` keys, and how to apply them to a [`Colors`] value.
+const STYLE_KEYS: &[(&str, fn(&mut Colors, String))] = &[
+	("background", |c, v| c.background = Some(v)),
+	("foreground", |c, v| c.foreground = Some(v)),
+	("accent", |c, v| c.accent = Some(v)),
+	("color0", |c, v| c.color0 = Some(v)),
+	("color1", |c, v| c.color1 = Some(v)),
+	("color2", |c, v| c.color2 = Some(v)),
+	("color3", |c, v| c.color3 = Some(v)),
+	("color4", |c, v| c.color4 = Some(v)),
+	("color5", |c, v| c.color5 = Some(v)),
+	("color6", |c, v| c.color6 = Some(v)),
+	("color7", |c, v| c.color7 = Some(v)),
+	("color8", |c, v| c.color8 = Some(v)),
+	("color9", |c, v| c.color9 = Some(v)),
+	("color10", |c, v| c.color10 = Some(v)),
+	("color11", |c, v| c.color11 = Some(v)),
+	("color12", |c, v| c.color12 = Some(v)),
+	("color13", |c, v| c.color13 = Some(v)),
+	("color14", |c, v| c.color14 = Some(v)),
+	("color15", |c, v| c.color15 = Some(v))
+];
+
+/// Parses a JSON theme family and maps each entry's recognized style keys
+/// onto niji's own color fields. Unmapped keys are logged as a warning
+/// rather than failing the import outright.
+pub fn parse_theme_family(contents: &str) -> Result<Vec<ImportedTheme>, ImportError> {
+	let family: ThemeFamily = serde_json::from_str(contents)?;
+
+	let imported = family
+		.themes
+		.into_iter()
+		.map(|entry| {
+			let mut colors = Colors::default();
+			let mut unmapped = Vec::new();
+
+			for (key, value) in entry.style {
+				match STYLE_KEYS.iter().find(|(k, _)| *k == key) {
+					Some((_, set)) => set(&mut colors, value),
+					None => unmapped.push(key)
+				}
+			}
+
+			if !unmapped.is_empty() {
+				log::warn!(
+					"Theme \"{}\" has unmapped style keys, which were ignored: {}",
+					entry.name,
+					unmapped.join(", ")
+				);
+			}
+
+			ImportedTheme { name: entry.name, appearance: entry.appearance, colors }
+		})
+		.collect();
+
+	Ok(imported)
+}
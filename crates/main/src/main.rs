@@ -0,0 +1,8 @@
+mod app;
+mod cli;
+mod import;
+mod portal;
+
+fn main() {
+	cli::run();
+}
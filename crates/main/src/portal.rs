@@ -0,0 +1,36 @@
+use zbus::blocking::Connection;
+use zbus::zvariant::Value;
+
+/// Queries the XDG desktop portal for the desktop's preferred color scheme
+/// via `org.freedesktop.portal.Settings.Read("org.freedesktop.appearance",
+/// "color-scheme")`.
+///
+/// Returns `1` for prefer-dark, `2` for prefer-light, and `0` when the
+/// desktop has no preference. Returns `None` if the portal is unreachable
+/// or doesn't expose the setting, which callers should treat the same as
+/// "no preference".
+pub fn query_color_scheme() -> Option<u32> {
+	let connection = Connection::session().ok()?;
+
+	let reply = connection
+		.call_method(
+			Some("org.freedesktop.portal.Desktop"),
+			"/org/freedesktop/portal/desktop",
+			Some("org.freedesktop.portal.Settings"),
+			"Read",
+			&("org.freedesktop.appearance", "color-scheme")
+		)
+		.ok()?;
+
+	// The portal wraps the reply value in an extra variant layer.
+	let outer: Value = reply.body().deserialize().ok()?;
+	let inner = match outer {
+		Value::Value(inner) => *inner,
+		other => other
+	};
+
+	match inner {
+		Value::U32(scheme) => Some(scheme),
+		_ => None
+	}
+}
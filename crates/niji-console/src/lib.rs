@@ -0,0 +1,88 @@
+use std::sync::OnceLock;
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Controls whether log output and [`println!`](crate::println) output may
+/// use ANSI color codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+	Always,
+	Auto,
+	Never
+}
+
+impl ColorChoice {
+	/// Whether color should actually be used, given the current color
+	/// choice and whether stdout is a terminal.
+	pub fn should_color(self) -> bool {
+		match self {
+			Self::Always => true,
+			Self::Never => false,
+			Self::Auto => std::io::IsTerminal::is_terminal(&std::io::stdout())
+		}
+	}
+}
+
+static COLOR_CHOICE: OnceLock<ColorChoice> = OnceLock::new();
+
+struct ConsoleLogger;
+
+impl Log for ConsoleLogger {
+	fn enabled(&self, metadata: &Metadata) -> bool {
+		metadata.level() <= log::max_level()
+	}
+
+	fn log(&self, record: &Record) {
+		if !self.enabled(record.metadata()) {
+			return;
+		}
+
+		let color = color_choice().should_color();
+		let label = level_label(record.level(), color);
+		eprintln!("{label} {}", record.args());
+	}
+
+	fn flush(&self) {}
+}
+
+fn level_label(level: log::Level, color: bool) -> String {
+	let (text, code) = match level {
+		log::Level::Error => ("error", "31"),
+		log::Level::Warn => ("warn", "33"),
+		log::Level::Info => ("info", "34"),
+		log::Level::Debug => ("debug", "90"),
+		log::Level::Trace => ("trace", "90")
+	};
+
+	if color {
+		format!("\x1b[{code}m{text}:\x1b[0m")
+	} else {
+		format!("{text}:")
+	}
+}
+
+/// Returns the [`ColorChoice`] configured via [`init`], or [`ColorChoice::Auto`]
+/// if [`init`] has not been called yet.
+pub fn color_choice() -> ColorChoice {
+	*COLOR_CHOICE.get().unwrap_or(&ColorChoice::Auto)
+}
+
+/// Initializes the global logger and color configuration. Should be called
+/// once, near the start of `main`.
+pub fn init(level: LevelFilter, color: ColorChoice) {
+	let _ = COLOR_CHOICE.set(color);
+	log::set_max_level(level);
+	log::set_logger(&ConsoleLogger).expect("logger should only be initialized once");
+}
+
+/// Like [`std::println`], but reserved for niji's actual program output (as
+/// opposed to log messages, which go through the `log` crate).
+#[macro_export]
+macro_rules! println {
+	() => {
+		std::println!()
+	};
+	($($arg:tt)*) => {
+		std::println!($($arg)*)
+	};
+}
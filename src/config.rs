@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// Persistent niji configuration, stored as `config.toml` in the niji
+/// config directory.
+///
+/// This tracks state that persists across invocations, such as the name of
+/// the currently active theme.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+	#[serde(default)]
+	pub theme: ThemeConfig
+}
+
+/// The `[theme]` section of the niji configuration, controlling which
+/// theme (or pair of themes) is currently active.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThemeConfig {
+	/// The name of the currently active theme. This is what `theme
+	/// get`/`show`/`apply` read, and what `theme set` writes directly.
+	/// `theme auto`, `apply --mode`, and plain `apply` (when `light`/`dark`
+	/// are configured) also write here, after resolving `light`/`dark` for
+	/// the effective mode, so that the resolved theme becomes the new
+	/// current theme rather than being tracked separately.
+	pub current: Option<String>,
+
+	/// The theme to use when the desktop prefers a light appearance.
+	pub light: Option<String>,
+
+	/// The theme to use when the desktop prefers a dark appearance.
+	pub dark: Option<String>,
+
+	/// How niji picks between `light` and `dark`.
+	#[serde(default)]
+	pub mode: ThemeMode
+}
+
+/// Controls how niji resolves the effective theme when `light`/`dark` are
+/// configured.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+	/// Follow the desktop's color-scheme preference.
+	#[default]
+	System,
+	/// Always use the `light` theme.
+	Light,
+	/// Always use the `dark` theme.
+	Dark
+}
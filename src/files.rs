@@ -0,0 +1,209 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::config::Config;
+use crate::types::{Module, Theme};
+use crate::utils::niji_config_dir;
+
+#[derive(Debug, Error)]
+pub enum FilesError {
+	#[error("could not determine the niji config directory")]
+	NoConfigDir,
+
+	#[error("failed to read {path}: {source}")]
+	Read { path: PathBuf, source: std::io::Error },
+
+	#[error("failed to write {path}: {source}")]
+	Write { path: PathBuf, source: std::io::Error },
+
+	#[error("failed to parse {path}: {source}")]
+	Parse { path: PathBuf, source: toml::de::Error },
+
+	#[error("theme \"{0}\" extends unknown theme \"{1}\"")]
+	UnknownParentTheme(String, String),
+
+	#[error("theme \"{0}\" extends itself through a cycle: {1}")]
+	ExtendsCycle(String, String),
+
+	#[error("\"{0}\" is not a valid theme name: it must not be empty or contain path separators")]
+	InvalidThemeName(String)
+}
+
+/// Gives access to niji's files: its configuration, themes, and module
+/// definitions, all of which live under the niji config directory.
+#[derive(Debug)]
+pub struct Files {
+	config_dir: PathBuf,
+
+	/// Names of themes a filename/declared-name mismatch has already been
+	/// warned about, so [`Self::iter_themes`] only reports each one once
+	/// per `Files` instance rather than on every traversal.
+	warned_name_mismatches: RefCell<HashSet<String>>
+}
+
+impl Files {
+	pub fn init() -> Result<Self, FilesError> {
+		let config_dir = niji_config_dir().ok_or(FilesError::NoConfigDir)?;
+		Ok(Self { config_dir, warned_name_mismatches: RefCell::new(HashSet::new()) })
+	}
+
+	pub fn config_dir(&self) -> PathBuf {
+		self.config_dir.clone()
+	}
+
+	pub fn config_path(&self) -> PathBuf {
+		self.config_dir.join("config.toml")
+	}
+
+	pub fn themes_dir(&self) -> PathBuf {
+		self.config_dir.join("themes")
+	}
+
+	pub fn modules_dir(&self) -> PathBuf {
+		self.config_dir.join("modules")
+	}
+
+	pub fn read_config(&self) -> Result<Config, FilesError> {
+		let path = self.config_path();
+		if !path.exists() {
+			return Ok(Config::default());
+		}
+
+		let contents = fs::read_to_string(&path).map_err(|source| FilesError::Read {
+			path: path.clone(),
+			source
+		})?;
+		toml::from_str(&contents).map_err(|source| FilesError::Parse { path, source })
+	}
+
+	pub fn write_config(&self, config: &Config) -> Result<(), FilesError> {
+		let path = self.config_path();
+		let contents = toml::to_string_pretty(config).expect("Config should always serialize");
+		fs::write(&path, contents).map_err(|source| FilesError::Write { path, source })
+	}
+
+	/// Writes `theme` out as `<name>.toml` in the themes directory,
+	/// creating the directory if it doesn't exist yet.
+	///
+	/// `name` must be a plain file name: it is rejected if it's empty, is
+	/// `.` or `..`, or contains a path separator, so callers can safely
+	/// pass names sourced from external, untrusted data (e.g. theme
+	/// imports) without risking writes outside the themes directory.
+	pub fn write_theme(&self, name: &str, theme: &Theme) -> Result<(), FilesError> {
+		if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+			return Err(FilesError::InvalidThemeName(name.to_string()));
+		}
+
+		let dir = self.themes_dir();
+		fs::create_dir_all(&dir).map_err(|source| FilesError::Write { path: dir.clone(), source })?;
+
+		let path = dir.join(format!("{name}.toml"));
+		if path.exists() {
+			log::warn!("Overwriting existing theme \"{name}\"");
+		}
+
+		let contents = toml::to_string_pretty(theme).expect("Theme should always serialize");
+		fs::write(&path, contents).map_err(|source| FilesError::Write { path, source })
+	}
+
+	/// Iterates over all themes found in the themes directory, with
+	/// `extends` chains resolved and merged.
+	pub fn iter_themes(&self) -> impl Iterator<Item = Theme> {
+		let raw: HashMap<String, Theme> = iter_toml_files(self.themes_dir())
+			.filter_map(|path| {
+				let name = path.file_stem()?.to_str()?.to_string();
+				let theme = parse_toml_file::<Theme>(&path)?;
+
+				if let Some(declared_name) = &theme.name {
+					if declared_name != &name && self.warned_name_mismatches.borrow_mut().insert(name.clone()) {
+						log::warn!(
+							"Theme \"{name}\" declares name \"{declared_name}\", which does not \
+							 match its filename. The filename is used to refer to the theme; the \
+							 declared name is ignored."
+						);
+					}
+				}
+
+				Some((name, theme))
+			})
+			.collect();
+
+		let names: Vec<String> = raw.keys().cloned().collect();
+		names
+			.into_iter()
+			.filter_map(move |name| match resolve_theme(&name, &raw, &mut Vec::new()) {
+				Ok(theme) => Some(theme),
+				Err(err) => {
+					log::warn!("Failed to resolve theme \"{name}\": {err}");
+					None
+				}
+			})
+			.collect::<Vec<_>>()
+			.into_iter()
+	}
+
+	/// Iterates over all module configurations found in the modules
+	/// directory.
+	pub fn iter_modules(&self) -> impl Iterator<Item = Module> {
+		iter_toml_files(self.modules_dir()).filter_map(|path| parse_toml_file(&path))
+	}
+}
+
+/// Resolves a theme's `extends` chain against the raw (unmerged) themes in
+/// `raw`, deep-merging parents bottom-up before overlaying `name`'s own
+/// colors. `visited` tracks the chain walked so far so cycles can be
+/// detected and reported instead of recursing forever.
+fn resolve_theme(
+	name: &str,
+	raw: &HashMap<String, Theme>,
+	visited: &mut Vec<String>
+) -> Result<Theme, FilesError> {
+	if visited.iter().any(|visited_name| visited_name == name) {
+		visited.push(name.to_string());
+		return Err(FilesError::ExtendsCycle(name.to_string(), visited.join(" -> ")));
+	}
+
+	let theme = raw.get(name).expect("name should come from raw.keys()");
+
+	visited.push(name.to_string());
+	let colors = match &theme.extends {
+		Some(parent_name) => {
+			if !raw.contains_key(parent_name) {
+				return Err(FilesError::UnknownParentTheme(name.to_string(), parent_name.clone()));
+			}
+			let parent = resolve_theme(parent_name, raw, visited)?;
+			theme.colors.clone().merge_over(&parent.colors)
+		}
+		None => theme.colors.clone()
+	};
+	visited.pop();
+
+	Ok(Theme {
+		name: Some(name.to_string()),
+		extends: theme.extends.clone(),
+		colors
+	})
+}
+
+fn iter_toml_files(dir: PathBuf) -> impl Iterator<Item = PathBuf> {
+	fs::read_dir(&dir)
+		.into_iter()
+		.flatten()
+		.filter_map(Result::ok)
+		.map(|entry| entry.path())
+		.filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+}
+
+fn parse_toml_file<T: serde::de::DeserializeOwned>(path: &PathBuf) -> Option<T> {
+	let contents = fs::read_to_string(path)
+		.inspect_err(|err| log::warn!("Failed to read {}: {err}", path.display()))
+		.ok()?;
+
+	toml::from_str(&contents)
+		.inspect_err(|err| log::warn!("Failed to parse {}: {err}", path.display()))
+		.ok()
+}
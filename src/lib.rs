@@ -0,0 +1,4 @@
+pub mod config;
+pub mod files;
+pub mod types;
+pub mod utils;
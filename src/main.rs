@@ -1,11 +1,4 @@
-use std::mem::size_of_val;
-
-use crate::files::Files;
-
-mod config;
-mod files;
-mod types;
-mod utils;
+use niji::files::Files;
 
 fn main() {
 	let files = Files::init().unwrap();
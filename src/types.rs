@@ -0,0 +1,155 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A single theme definition, as loaded from a theme file.
+///
+/// When parsed from a file, `name` holds the name the file declares (if
+/// any); the loader then replaces it with the theme's canonical name, the
+/// file name it was loaded from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Theme {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+
+	/// The name of a parent theme to inherit colors from. Colors this theme
+	/// doesn't declare itself are taken from the parent, recursively.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub extends: Option<String>,
+
+	#[serde(default)]
+	pub colors: Colors
+}
+
+impl fmt::Display for Theme {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.colors)
+	}
+}
+
+/// The color palette of a theme.
+///
+/// Every field is optional so that themes only need to declare the colors
+/// they actually care about; modules are expected to fall back to sensible
+/// defaults for colors a theme leaves unset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Colors {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub background: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub foreground: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub accent: Option<String>,
+
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color0: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color1: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color2: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color3: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color4: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color5: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color6: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color7: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color8: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color9: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color10: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color11: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color12: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color13: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color14: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color15: Option<String>
+}
+
+impl Colors {
+	/// Deep-merges `self` on top of `parent`, keeping `self`'s value for
+	/// every field it set and falling back to `parent`'s otherwise.
+	pub fn merge_over(self, parent: &Colors) -> Colors {
+		macro_rules! merge {
+			($field:ident) => {
+				self.$field.or_else(|| parent.$field.clone())
+			};
+		}
+
+		Colors {
+			background: merge!(background),
+			foreground: merge!(foreground),
+			accent: merge!(accent),
+			color0: merge!(color0),
+			color1: merge!(color1),
+			color2: merge!(color2),
+			color3: merge!(color3),
+			color4: merge!(color4),
+			color5: merge!(color5),
+			color6: merge!(color6),
+			color7: merge!(color7),
+			color8: merge!(color8),
+			color9: merge!(color9),
+			color10: merge!(color10),
+			color11: merge!(color11),
+			color12: merge!(color12),
+			color13: merge!(color13),
+			color14: merge!(color14),
+			color15: merge!(color15)
+		}
+	}
+
+	/// Iterates over the colors this theme actually declares, as
+	/// `(label, hex value)` pairs.
+	pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+		[
+			("background", &self.background),
+			("foreground", &self.foreground),
+			("accent", &self.accent),
+			("color0", &self.color0),
+			("color1", &self.color1),
+			("color2", &self.color2),
+			("color3", &self.color3),
+			("color4", &self.color4),
+			("color5", &self.color5),
+			("color6", &self.color6),
+			("color7", &self.color7),
+			("color8", &self.color8),
+			("color9", &self.color9),
+			("color10", &self.color10),
+			("color11", &self.color11),
+			("color12", &self.color12),
+			("color13", &self.color13),
+			("color14", &self.color14),
+			("color15", &self.color15)
+		]
+		.into_iter()
+		.filter_map(|(label, value)| value.as_deref().map(|value| (label, value)))
+	}
+}
+
+impl fmt::Display for Colors {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (label, value) in self.iter() {
+			writeln!(f, "{label}: {value}")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Configuration for a single theming module, as loaded from a module file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Module {
+	pub name: String,
+	pub active: bool
+}
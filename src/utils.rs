@@ -0,0 +1,9 @@
+use std::path::PathBuf;
+
+/// Returns the directory niji stores its own configuration and data in,
+/// i.e. `$XDG_CONFIG_HOME/niji` (falling back to `~/.config/niji`).
+pub fn niji_config_dir() -> Option<PathBuf> {
+	let mut dir = dirs::config_dir()?;
+	dir.push("niji");
+	Some(dir)
+}